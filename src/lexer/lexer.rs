@@ -1,5 +1,5 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum Token {
+#[derive(Debug, PartialEq, Clone)]
+pub enum Token {
     LParen,
     RParen,
     LBrace,
@@ -15,158 +15,430 @@ enum Token {
     Apostrophe,
     Question,
     Equals,
-    Number(i32),
+    LessEqual,
+    GreaterEqual,
+    EqualEqual,
+    NotEqual,
+    Integer(i64),
+    Float(f64),
+    Ratio(i64, i64),
+    String(String),
+    Symbol(String),
+    Def,
+    Fn,
+    Let,
+    If,
+    True,
+    False,
+    Nil,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    fn shift(self, offset: usize) -> Self {
+        Span::new(self.start + offset, self.end + offset)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LexError {
+    UnexpectedChar(char, usize),
+    MalformedNumber(usize),
+    UnterminatedString(usize),
+}
+
+impl LexError {
+    fn shift(self, offset: usize) -> Self {
+        match self {
+            LexError::UnexpectedChar(c, pos) => LexError::UnexpectedChar(c, pos + offset),
+            LexError::MalformedNumber(pos) => LexError::MalformedNumber(pos + offset),
+            LexError::UnterminatedString(pos) => LexError::UnterminatedString(pos + offset),
+        }
+    }
+}
+
+pub fn lex(code: &str) -> Result<Vec<(Token, Span)>, LexError> {
+    let mut lexer = Lexer::new(code);
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+    Ok(tokens)
 }
 
 #[derive(Debug, PartialEq)]
-struct Lexer<'a> {
-    current: Option<Token>,
+pub struct Lexer<'a> {
+    current: Option<Result<(Token, Span), LexError>>,
     code: &'a str,
+    cursor: usize,
 }
 
 impl<'a> Iterator for Lexer<'a> {
-    type Item = Token;
+    type Item = Result<(Token, Span), LexError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let token = self.current;
-        if let Some(lexed) = Self::lex(self.code) {
-            self.current = Some(lexed.token);
-            self.code = lexed.rest;
-        } else {
-            self.current = None;
-            self.code = &"";
-        };
+        let token = self.current.take();
+        match Self::lex(self.code) {
+            Ok(Some(lexed)) => {
+                let consumed = self.code.len() - lexed.rest.len();
+                let span = lexed.span.shift(self.cursor);
+                self.cursor += consumed;
+                self.current = Some(Ok((lexed.token, span)));
+                self.code = lexed.rest;
+            }
+            Ok(None) => {
+                self.current = None;
+                self.code = &"";
+            }
+            Err(e) => {
+                self.current = Some(Err(e.shift(self.cursor)));
+                self.code = &"";
+            }
+        }
         token
     }
 }
 
 impl<'a> Lexer<'a>  {
     pub fn new(code: &'a str) -> Self {
-        if let Some(lexed) = Self::lex(code) {
-            let current = Some(lexed.token);
-            let code = lexed.rest;
-            Lexer { current, code }
-        } else {
-            Lexer { current: None, code: &"" }
-        }
+        let mut lexer = Lexer { current: None, code, cursor: 0 };
+        lexer.next();
+        lexer
+    }
+
+    pub fn next_token(&mut self) -> Result<Option<(Token, Span)>, LexError> {
+        self.next().transpose()
     }
 
-    fn lex(code: &str) -> Option<Lexed> {
-        let functions = [
-            Self::l_paren, 
+    fn lex(code: &str) -> Result<Option<Lexed>, LexError> {
+        let trimmed = Self::skip_trivia(code);
+        let skipped = code.len() - trimmed.len();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        // Two-char operators are tried first so e.g. `<=` isn't claimed by the
+        // single-char `<` matcher before it gets a chance (maximal munch).
+        let two_char_functions: [fn(&str) -> Result<Option<Lexed>, LexError>; 4] = [
+            Self::less_equal,
+            Self::greater_equal,
+            Self::equal_equal,
+            Self::not_equal,
+            ];
+        // `number` goes before `minus` so a sign immediately followed by a
+        // digit (`-5`) lexes as a negative literal rather than Minus + 5;
+        // `minus` still wins when the `-` stands alone (e.g. `- 1 2`).
+        let functions: [fn(&str) -> Result<Option<Lexed>, LexError>; 18] = [
+            Self::number,
+            Self::string,
+            Self::l_paren,
             Self::r_paren,
-            Self::l_brace, 
+            Self::l_brace,
             Self::r_brace,
             Self::l_angle_bracket,
             Self::r_angle_bracket,
             Self::plus,
             Self::minus,
             Self::asterisk,
-            Self::slash, 
+            Self::slash,
             Self::ban,
             Self::underbar,
             Self::apostrophe,
             Self::question,
             Self::equals,
-            Self::number, 
+            Self::symbol,
             ];
-        functions.iter().find_map(|f| {
-            let code = code.trim_start();
-            f(code)
-        })
+        for f in two_char_functions.iter().chain(functions.iter()) {
+            match f(trimmed).map_err(|e| e.shift(skipped))? {
+                Some(lexed) => return Ok(Some(lexed.shift(skipped))),
+                None => continue,
+            }
+        }
+        let unexpected = trimmed.chars().next().unwrap();
+        Err(LexError::UnexpectedChar(unexpected, skipped))
+    }
+
+    // Strips leading whitespace and `;`-line comments, alternating between the
+    // two until neither can consume any more (e.g. a comment followed by more
+    // whitespace followed by another comment).
+    fn skip_trivia(code: &str) -> &str {
+        let mut code = code.trim_start();
+        while code.starts_with(';') {
+            let comment_end = code.find('\n').unwrap_or(code.len());
+            code = code[comment_end..].trim_start();
+        }
+        code
+    }
+
+    fn number(code: &str) -> Result<Option<Lexed>, LexError> {
+        let mut chars = code.chars().peekable();
+        let mut index = 0;
+
+        // Only treat a leading `-` as a sign when a digit follows immediately;
+        // otherwise leave it for the `minus` matcher.
+        if chars.peek() == Some(&'-') {
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            match lookahead.peek() {
+                Some(d) if d.is_ascii_digit() => {
+                    index += 1;
+                    chars.next();
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        let digits_start = index;
+        while let Some(&c) = chars.peek() {
+            if !c.is_ascii_digit() { break }
+            index += 1;
+            chars.next();
+        }
+        if index == digits_start { return Ok(None) }
+
+        // Ratio: `<digits>/<digits>`, but only when a digit follows the `/`
+        // (so `1/x` lexes as Integer + Slash + Symbol).
+        if chars.peek() == Some(&'/') {
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            if matches!(lookahead.peek(), Some(d) if d.is_ascii_digit()) {
+                let numerator_end = index;
+                index += 1;
+                chars.next();
+                let denominator_start = index;
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_digit() { break }
+                    index += 1;
+                    chars.next();
+                }
+                let rest = &code[index..];
+                let numerator = code[..numerator_end].parse::<i64>();
+                let denominator = code[denominator_start..index].parse::<i64>();
+                return match (numerator, denominator) {
+                    (Ok(n), Ok(d)) => Ok(Some(Lexed::new(Token::Ratio(n, d), rest, Span::new(0, index)))),
+                    _ => Err(LexError::MalformedNumber(0)),
+                };
+            }
+        }
+
+        // Float: `.` only counts as a decimal point when followed by a digit,
+        // so a trailing `.` (e.g. a sentence-ending period) isn't swallowed.
+        let mut is_float = false;
+        if chars.peek() == Some(&'.') {
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            if matches!(lookahead.peek(), Some(d) if d.is_ascii_digit()) {
+                is_float = true;
+                index += 1;
+                chars.next();
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_digit() { break }
+                    index += 1;
+                    chars.next();
+                }
+            }
+        }
+
+        let text = &code[..index];
+        let rest = &code[index..];
+        if is_float {
+            match text.parse::<f64>() {
+                Ok(f) => Ok(Some(Lexed::new(Token::Float(f), rest, Span::new(0, index)))),
+                Err(_) => Err(LexError::MalformedNumber(0)),
+            }
+        } else {
+            match text.parse::<i64>() {
+                Ok(n) => Ok(Some(Lexed::new(Token::Integer(n), rest, Span::new(0, index)))),
+                Err(_) => Err(LexError::MalformedNumber(0)),
+            }
+        }
     }
 
-    fn number(code: &str) -> Option<Lexed> {
+    // Unlike the other matchers this has to build an owned `String` rather
+    // than slicing `code`, since escapes mean the token's text isn't a
+    // contiguous run of the source bytes.
+    fn string(code: &str) -> Result<Option<Lexed>, LexError> {
         let mut chars = code.chars();
-        let index = chars.position(|c| !c.is_numeric()).unwrap_or(code.len());
-        if index == 0 { return None }
-        let num = &code[..index];
-        let rest = &code[index..];
-        let num = num.parse::<i32>();
-        match num {
-            Ok(n) => Some(Lexed::new(Token::Number(n), rest)),
-            Err(_) => None
+        match chars.next() {
+            Some('"') => {}
+            _ => return Ok(None),
+        }
+        let mut value = String::new();
+        let mut index = 1;
+        loop {
+            match chars.next() {
+                None => return Err(LexError::UnterminatedString(0)),
+                Some('"') => {
+                    index += 1;
+                    let rest = &code[index..];
+                    return Ok(Some(Lexed::new(Token::String(value), rest, Span::new(0, index))));
+                }
+                Some('\\') => {
+                    index += 1;
+                    let escaped = match chars.next() {
+                        Some('"') => '"',
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('\\') => '\\',
+                        Some(c) => c,
+                        None => return Err(LexError::UnterminatedString(0)),
+                    };
+                    index += escaped.len_utf8();
+                    value.push(escaped);
+                }
+                Some(c) => {
+                    index += c.len_utf8();
+                    value.push(c);
+                }
+            }
+        }
+    }
+
+    fn symbol(code: &str) -> Result<Option<Lexed>, LexError> {
+        let mut chars = code.chars();
+        let first = match chars.next() {
+            Some(c) if c.is_alphabetic() => c,
+            _ => return Ok(None),
+        };
+        let mut index = first.len_utf8();
+        for c in chars {
+            if c.is_alphanumeric() || matches!(c, '-' | '*' | '?' | '!') {
+                index += c.len_utf8();
+            } else {
+                break;
+            }
         }
+        let name = &code[..index];
+        let rest = &code[index..];
+        let token = match name {
+            "def" => Token::Def,
+            "fn" => Token::Fn,
+            "let" => Token::Let,
+            "if" => Token::If,
+            "true" => Token::True,
+            "false" => Token::False,
+            "nil" => Token::Nil,
+            _ => Token::Symbol(name.to_string()),
+        };
+        Ok(Some(Lexed::new(token, rest, Span::new(0, index))))
     }
-    
-    fn l_paren(code: &str) -> Option<Lexed> {
-        Self::char(code, '(', Token::LParen)
+
+    fn l_paren(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '(', Token::LParen))
+    }
+
+    fn r_paren(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, ')', Token::RParen))
+    }
+
+    fn l_brace(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '[', Token::LBrace))
+    }
+
+    fn r_brace(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, ']', Token::RBrace))
+    }
+
+    fn l_angle_bracket(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '<', Token::LAngleBracket))
     }
-    
-    fn r_paren(code: &str) -> Option<Lexed> {
-        Self::char(code, ')', Token::RParen)
+
+    fn r_angle_bracket(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '>', Token::RAngleBracket))
     }
 
-    fn l_brace(code: &str) -> Option<Lexed> {
-        Self::char(code, '[', Token::LBrace)
+    fn plus(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '+', Token::Plus))
     }
-    
-    fn r_brace(code: &str) -> Option<Lexed> {
-        Self::char(code, ']', Token::RBrace)
+
+    fn minus(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '-', Token::Minus))
     }
 
-    fn l_angle_bracket(code: &str) -> Option<Lexed> {
-        Self::char(code, '<', Token::LAngleBracket)
+    fn asterisk(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '*', Token::Asterisk))
     }
-    
-    fn r_angle_bracket(code: &str) -> Option<Lexed> {
-        Self::char(code, '>', Token::RAngleBracket)
+
+    fn slash(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '/', Token::Slash))
     }
-    
-    fn plus(code: &str) -> Option<Lexed> {
-        Self::char(code, '+', Token::Plus)
+
+    fn ban(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '!', Token::Ban))
     }
-    
-    fn minus(code: &str) -> Option<Lexed> {
-        Self::char(code, '-', Token::Minus)
+
+    fn underbar(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '_', Token::Underbar))
     }
-    
-    fn asterisk(code: &str) -> Option<Lexed> {
-        Self::char(code, '*', Token::Asterisk)
+
+    fn apostrophe(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '\'', Token::Apostrophe))
     }
-    
-    fn slash(code: &str) -> Option<Lexed> {
-        Self::char(code, '/', Token::Slash)
+
+    fn question(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '?', Token::Question))
     }
 
-    fn ban(code: &str) -> Option<Lexed> {
-        Self::char(code, '!', Token::Ban)
+    fn equals(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::char(code, '=', Token::Equals))
     }
 
-    fn underbar(code: &str) -> Option<Lexed> {
-        Self::char(code, '_', Token::Underbar)
+    fn less_equal(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::prefix(code, "<=", Token::LessEqual))
     }
 
-    fn apostrophe(code: &str) -> Option<Lexed> {
-        Self::char(code, '\'', Token::Apostrophe)
+    fn greater_equal(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::prefix(code, ">=", Token::GreaterEqual))
     }
 
-    fn question(code: &str) -> Option<Lexed> {
-        Self::char(code, '?', Token::Question)
+    fn equal_equal(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::prefix(code, "==", Token::EqualEqual))
     }
 
-    fn equals(code: &str) -> Option<Lexed> {
-        Self::char(code, '=', Token::Equals)
+    fn not_equal(code: &str) -> Result<Option<Lexed>, LexError> {
+        Ok(Self::prefix(code, "!=", Token::NotEqual))
     }
-    
+
     fn char(code: &str, target: char, token: Token) -> Option<Lexed> {
         let mut chars = code.chars();
         let next = chars.next();
         match next {
-            Some(c) if c == target => Some(Lexed::new(token, &chars.as_str())),
+            Some(c) if c == target => Some(Lexed::new(token, &chars.as_str(), Span::new(0, c.len_utf8()))),
             _ => None
         }
     }
+
+    fn prefix<'b>(code: &'b str, target: &str, token: Token) -> Option<Lexed<'b>> {
+        if code.starts_with(target) {
+            Some(Lexed::new(token, &code[target.len()..], Span::new(0, target.len())))
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
 struct Lexed<'a> {
     token: Token,
     rest: &'a str,
+    span: Span,
 }
 impl<'a> Lexed<'a> {
-    pub fn new(token: Token, rest: &'a str) -> Self {
-        Lexed{token, rest}
+    pub fn new(token: Token, rest: &'a str, span: Span) -> Self {
+        Lexed{token, rest, span}
+    }
+
+    fn shift(self, offset: usize) -> Self {
+        let span = self.span.shift(offset);
+        Lexed { span, ..self }
     }
 }
 
@@ -177,190 +449,438 @@ mod tests {
     #[test]
     fn test_paren() {
         let test = "()";
-        let expect = Some(Lexed::new(Token::LParen, &")"));
+        let expect = Ok(Some(Lexed::new(Token::LParen, &")", Span::new(0, 1))));
         assert_eq!(Lexer::l_paren(&test), expect);
 
         let test = "))";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::l_paren(&test), expect);
 
         let test = "))";
-        let expect = Some(Lexed::new(Token::RParen, &")"));
+        let expect = Ok(Some(Lexed::new(Token::RParen, &")", Span::new(0, 1))));
         assert_eq!(Lexer::r_paren(&test), expect);
 
         let test = "()";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::r_paren(&test), expect);
     }
 
     #[test]
     fn test_bracket() {
         let test = "[]";
-        let expect = Some(Lexed::new(Token::LBrace, &"]"));
+        let expect = Ok(Some(Lexed::new(Token::LBrace, &"]", Span::new(0, 1))));
         assert_eq!(Lexer::l_brace(&test), expect);
 
         let test = "]]";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::l_brace(&test), expect);
 
         let test = "]]";
-        let expect = Some(Lexed::new(Token::RBrace, &"]"));
+        let expect = Ok(Some(Lexed::new(Token::RBrace, &"]", Span::new(0, 1))));
         assert_eq!(Lexer::r_brace(&test), expect);
 
         let test = "[]]";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::r_brace(&test), expect);
     }
 
     #[test]
     fn test_number() {
         let test = "123c";
-        let expect = Some(Lexed::new(Token::Number(123), &"c"));
+        let expect = Ok(Some(Lexed::new(Token::Integer(123), &"c", Span::new(0, 3))));
         assert_eq!(Lexer::number(&test), expect);
 
         let test = "123";
-        let expect = Some(Lexed::new(Token::Number(123), &""));
+        let expect = Ok(Some(Lexed::new(Token::Integer(123), &"", Span::new(0, 3))));
         assert_eq!(Lexer::number(&test), expect);
 
         let test = "+123";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::number(&test), expect);
+
+        let test = "99999999999999999999 1";
+        let expect = Err(LexError::MalformedNumber(0));
+        assert_eq!(Lexer::number(&test), expect);
+
+        let test = "-5 1";
+        let expect = Ok(Some(Lexed::new(Token::Integer(-5), &" 1", Span::new(0, 2))));
+        assert_eq!(Lexer::number(&test), expect);
+
+        let test = "12.34 x";
+        let expect = Ok(Some(Lexed::new(Token::Float(12.34), &" x", Span::new(0, 5))));
+        assert_eq!(Lexer::number(&test), expect);
+
+        let test = "-2.5)";
+        let expect = Ok(Some(Lexed::new(Token::Float(-2.5), &")", Span::new(0, 4))));
+        assert_eq!(Lexer::number(&test), expect);
+
+        let test = "1/2 x";
+        let expect = Ok(Some(Lexed::new(Token::Ratio(1, 2), &" x", Span::new(0, 3))));
+        assert_eq!(Lexer::number(&test), expect);
+
+        let test = "3.";
+        let expect = Ok(Some(Lexed::new(Token::Integer(3), &".", Span::new(0, 1))));
+        assert_eq!(Lexer::number(&test), expect);
+    }
+
+    #[test]
+    fn test_lex_numeric_literals() {
+        let tokens = lex("(+ -5 1)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Plus, Span::new(1, 2)),
+            (Token::Integer(-5), Span::new(3, 5)),
+            (Token::Integer(1), Span::new(6, 7)),
+            (Token::RParen, Span::new(7, 8)),
+        ]);
+
+        // A bare `-` still lexes as subtraction, not a negative-number prefix.
+        let tokens = lex("(- 1 2)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Minus, Span::new(1, 2)),
+            (Token::Integer(1), Span::new(3, 4)),
+            (Token::Integer(2), Span::new(5, 6)),
+            (Token::RParen, Span::new(6, 7)),
+        ]);
+
+        // A bare `/` still lexes as division, not a ratio separator.
+        let tokens = lex("(/ 1 2)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Slash, Span::new(1, 2)),
+            (Token::Integer(1), Span::new(3, 4)),
+            (Token::Integer(2), Span::new(5, 6)),
+            (Token::RParen, Span::new(6, 7)),
+        ]);
+
+        let tokens = lex("1/2").unwrap();
+        assert_eq!(tokens, vec![(Token::Ratio(1, 2), Span::new(0, 3))]);
+
+        let tokens = lex("12.34").unwrap();
+        assert_eq!(tokens, vec![(Token::Float(12.34), Span::new(0, 5))]);
     }
 
     #[test]
     fn test_operator() {
         let test = "+";
-        let expect = Some(Lexed::new(Token::Plus, &""));
+        let expect = Ok(Some(Lexed::new(Token::Plus, &"", Span::new(0, 1))));
         assert_eq!(Lexer::plus(&test), expect);
 
         let test = "+ 1 2";
-        let expect = Some(Lexed::new(Token::Plus, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Plus, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::plus(&test), expect);
-        
+
         let test = "1+2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::plus(&test), expect);
 
         let test = "-";
-        let expect = Some(Lexed::new(Token::Minus, &""));
+        let expect = Ok(Some(Lexed::new(Token::Minus, &"", Span::new(0, 1))));
         assert_eq!(Lexer::minus(&test), expect);
 
         let test = "- 1 2";
-        let expect = Some(Lexed::new(Token::Minus, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Minus, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::minus(&test), expect);
 
         let test = "*";
-        let expect = Some(Lexed::new(Token::Asterisk, &""));
+        let expect = Ok(Some(Lexed::new(Token::Asterisk, &"", Span::new(0, 1))));
         assert_eq!(Lexer::asterisk(&test), expect);
 
         let test = "* 1 2";
-        let expect = Some(Lexed::new(Token::Asterisk, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Asterisk, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::asterisk(&test), expect);
 
         let test = "/";
-        let expect = Some(Lexed::new(Token::Slash, &""));
+        let expect = Ok(Some(Lexed::new(Token::Slash, &"", Span::new(0, 1))));
         assert_eq!(Lexer::slash(&test), expect);
 
         let test = "/ 1 2";
-        let expect = Some(Lexed::new(Token::Slash, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Slash, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::slash(&test), expect);
     }
 
     #[test]
     fn test_ban() {
         let test = "! 1 2";
-        let expect = Some(Lexed::new(Token::Ban, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Ban, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::ban(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::ban(&test), expect);
     }
 
     #[test]
     fn test_underbar() {
         let test = "_ 1 2";
-        let expect = Some(Lexed::new(Token::Underbar, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Underbar, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::underbar(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::underbar(&test), expect);
     }
 
     #[test]
     fn test_apostrophe() {
         let test = "'(+ 1 2)";
-        let expect = Some(Lexed::new(Token::Apostrophe, &"(+ 1 2)"));
+        let expect = Ok(Some(Lexed::new(Token::Apostrophe, &"(+ 1 2)", Span::new(0, 1))));
         assert_eq!(Lexer::apostrophe(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::apostrophe(&test), expect);
     }
 
     #[test]
     fn test_question() {
         let test = "?(+ 1 2)";
-        let expect = Some(Lexed::new(Token::Question, &"(+ 1 2)"));
+        let expect = Ok(Some(Lexed::new(Token::Question, &"(+ 1 2)", Span::new(0, 1))));
         assert_eq!(Lexer::question(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::question(&test), expect);
     }
 
     #[test]
     fn test_angle_brackets() {
         let test = "<+ 1 2>";
-        let expect = Some(Lexed::new(Token::LAngleBracket, &"+ 1 2>"));
+        let expect = Ok(Some(Lexed::new(Token::LAngleBracket, &"+ 1 2>", Span::new(0, 1))));
         assert_eq!(Lexer::l_angle_bracket(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::l_angle_bracket(&test), expect);
 
         let test = ">";
-        let expect = Some(Lexed::new(Token::RAngleBracket, &""));
+        let expect = Ok(Some(Lexed::new(Token::RAngleBracket, &"", Span::new(0, 1))));
         assert_eq!(Lexer::r_angle_bracket(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::r_angle_bracket(&test), expect);
     }
 
     #[test]
     fn test_equals() {
         let test = "= 1 2)";
-        let expect = Some(Lexed::new(Token::Equals, &" 1 2)"));
+        let expect = Ok(Some(Lexed::new(Token::Equals, &" 1 2)", Span::new(0, 1))));
         assert_eq!(Lexer::equals(&test), expect);
 
         let test = "1 2";
-        let expect = None;
+        let expect = Ok(None);
         assert_eq!(Lexer::equals(&test), expect);
     }
 
+    #[test]
+    fn test_multi_char_operators() {
+        let test = "<= 1 2";
+        let expect = Ok(Some(Lexed::new(Token::LessEqual, &" 1 2", Span::new(0, 2))));
+        assert_eq!(Lexer::less_equal(&test), expect);
+
+        let test = "< 1 2";
+        let expect = Ok(None);
+        assert_eq!(Lexer::less_equal(&test), expect);
+
+        let test = ">= 1 2";
+        let expect = Ok(Some(Lexed::new(Token::GreaterEqual, &" 1 2", Span::new(0, 2))));
+        assert_eq!(Lexer::greater_equal(&test), expect);
+
+        let test = "== 1 2";
+        let expect = Ok(Some(Lexed::new(Token::EqualEqual, &" 1 2", Span::new(0, 2))));
+        assert_eq!(Lexer::equal_equal(&test), expect);
+
+        let test = "!= 1 2";
+        let expect = Ok(Some(Lexed::new(Token::NotEqual, &" 1 2", Span::new(0, 2))));
+        assert_eq!(Lexer::not_equal(&test), expect);
+
+        let test = "! 1 2";
+        let expect = Ok(None);
+        assert_eq!(Lexer::not_equal(&test), expect);
+    }
+
+    #[test]
+    fn test_lex_prefers_longest_match() {
+        let test = "<=";
+        let expect = Ok(Some(Lexed::new(Token::LessEqual, &"", Span::new(0, 2))));
+        assert_eq!(Lexer::lex(&test), expect);
+
+        let test = "<";
+        let expect = Ok(Some(Lexed::new(Token::LAngleBracket, &"", Span::new(0, 1))));
+        assert_eq!(Lexer::lex(&test), expect);
+
+        let tokens = lex("(<= 1 2)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::LessEqual, Span::new(1, 3)),
+            (Token::Integer(1), Span::new(4, 5)),
+            (Token::Integer(2), Span::new(6, 7)),
+            (Token::RParen, Span::new(7, 8)),
+        ]);
+    }
+
+    #[test]
+    fn test_symbol() {
+        let test = "my-var 1";
+        let expect = Ok(Some(Lexed::new(Token::Symbol("my-var".to_string()), &" 1", Span::new(0, 6))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "valid? x";
+        let expect = Ok(Some(Lexed::new(Token::Symbol("valid?".to_string()), &" x", Span::new(0, 6))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "swap!";
+        let expect = Ok(Some(Lexed::new(Token::Symbol("swap!".to_string()), &"", Span::new(0, 5))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "-5";
+        let expect = Ok(None);
+        assert_eq!(Lexer::symbol(&test), expect);
+    }
+
+    #[test]
+    fn test_symbol_keywords() {
+        let test = "def";
+        let expect = Ok(Some(Lexed::new(Token::Def, &"", Span::new(0, 3))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "fn";
+        let expect = Ok(Some(Lexed::new(Token::Fn, &"", Span::new(0, 2))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "let";
+        let expect = Ok(Some(Lexed::new(Token::Let, &"", Span::new(0, 3))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "if";
+        let expect = Ok(Some(Lexed::new(Token::If, &"", Span::new(0, 2))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "true";
+        let expect = Ok(Some(Lexed::new(Token::True, &"", Span::new(0, 4))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "false";
+        let expect = Ok(Some(Lexed::new(Token::False, &"", Span::new(0, 5))));
+        assert_eq!(Lexer::symbol(&test), expect);
+
+        let test = "nil";
+        let expect = Ok(Some(Lexed::new(Token::Nil, &"", Span::new(0, 3))));
+        assert_eq!(Lexer::symbol(&test), expect);
+    }
+
+    #[test]
+    fn test_lex_symbols_do_not_shadow_single_char_tokens() {
+        let tokens = lex("(- 1 2)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Minus, Span::new(1, 2)),
+            (Token::Integer(1), Span::new(3, 4)),
+            (Token::Integer(2), Span::new(5, 6)),
+            (Token::RParen, Span::new(6, 7)),
+        ]);
+
+        let tokens = lex("(def swap! fn)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Def, Span::new(1, 4)),
+            (Token::Symbol("swap!".to_string()), Span::new(5, 10)),
+            (Token::Fn, Span::new(11, 13)),
+            (Token::RParen, Span::new(13, 14)),
+        ]);
+    }
+
+    #[test]
+    fn test_string() {
+        let test = "\"hello\" rest";
+        let expect = Ok(Some(Lexed::new(Token::String("hello".to_string()), &" rest", Span::new(0, 7))));
+        assert_eq!(Lexer::string(&test), expect);
+
+        let test = "hello";
+        let expect = Ok(None);
+        assert_eq!(Lexer::string(&test), expect);
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let test = r#""a\"b\n\t\\c""#;
+        let expect = Ok(Some(Lexed::new(Token::String("a\"b\n\t\\c".to_string()), &"", Span::new(0, 13))));
+        assert_eq!(Lexer::string(&test), expect);
+    }
+
+    #[test]
+    fn test_string_unterminated() {
+        let test = "\"abc";
+        let expect = Err(LexError::UnterminatedString(0));
+        assert_eq!(Lexer::string(&test), expect);
+
+        let test = "\"abc\\";
+        let expect = Err(LexError::UnterminatedString(0));
+        assert_eq!(Lexer::string(&test), expect);
+    }
+
+    #[test]
+    fn test_lex_string() {
+        let tokens = lex(r#"(def greeting "hi there")"#).unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Def, Span::new(1, 4)),
+            (Token::Symbol("greeting".to_string()), Span::new(5, 13)),
+            (Token::String("hi there".to_string()), Span::new(14, 24)),
+            (Token::RParen, Span::new(24, 25)),
+        ]);
+
+        let err = lex("\"unterminated").unwrap_err();
+        assert_eq!(err, LexError::UnterminatedString(0));
+    }
+
+    #[test]
+    fn test_lex_skips_line_comments() {
+        let tokens = lex("; a comment\n(+ 1 2)").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(12, 13)),
+            (Token::Plus, Span::new(13, 14)),
+            (Token::Integer(1), Span::new(15, 16)),
+            (Token::Integer(2), Span::new(17, 18)),
+            (Token::RParen, Span::new(18, 19)),
+        ]);
+
+        let tokens = lex("(+ 1 2) ; trailing comment").unwrap();
+        assert_eq!(tokens, vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Plus, Span::new(1, 2)),
+            (Token::Integer(1), Span::new(3, 4)),
+            (Token::Integer(2), Span::new(5, 6)),
+            (Token::RParen, Span::new(6, 7)),
+        ]);
+
+        let tokens = lex("; only a comment, no trailing newline").unwrap();
+        assert_eq!(tokens, Vec::new());
+    }
+
     #[test]
     fn test_lex() {
         let test = "123c";
-        let expect = Some(Lexed::new(Token::Number(123), &"c"));
+        let expect = Ok(Some(Lexed::new(Token::Integer(123), &"c", Span::new(0, 3))));
         assert_eq!(Lexer::lex(&test), expect);
 
         let test = "+ 1 2";
-        let expect = Some(Lexed::new(Token::Plus, &" 1 2"));
+        let expect = Ok(Some(Lexed::new(Token::Plus, &" 1 2", Span::new(0, 1))));
         assert_eq!(Lexer::lex(&test), expect);
-        
+
         let test = "1+2";
-        let expect = Some(Lexed::new(Token::Number(1), &"+2"));
+        let expect = Ok(Some(Lexed::new(Token::Integer(1), &"+2", Span::new(0, 1))));
         assert_eq!(Lexer::lex(&test), expect);
 
-        let test = "))";
-        let expect = Some(Lexed::new(Token::RParen, &")"));
-        assert_eq!(Lexer::r_paren(&test), expect);
+        let test = "  ~";
+        let expect = Err(LexError::UnexpectedChar('~', 2));
+        assert_eq!(Lexer::lex(&test), expect);
 
-        let test = "~";
-        let expect = None;
-        assert_eq!(Lexer::r_paren(&test), expect);
+        let test = "";
+        let expect = Ok(None);
+        assert_eq!(Lexer::lex(&test), expect);
     }
 
     #[test]
@@ -368,63 +888,83 @@ mod tests {
         let code = "(+12)";
         let lexer = Lexer::new(&code);
         let mut lexer = lexer.peekable();
-        assert_eq!(lexer.peek(), Some(&Token::LParen));
-        assert_eq!(lexer.next(), Some(Token::LParen));
-        assert_eq!(lexer.peek(), Some(&Token::Plus));
-        assert_eq!(lexer.next(), Some(Token::Plus));
-        assert_eq!(lexer.peek(), Some(&Token::Number(12)));
-        assert_eq!(lexer.next(), Some(Token::Number(12)));
-        assert_eq!(lexer.peek(), Some(&Token::RParen));
-        assert_eq!(lexer.next(), Some(Token::RParen));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Plus, Span::new(1, 2)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Plus, Span::new(1, 2)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(12), Span::new(2, 4)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(12), Span::new(2, 4)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::RParen, Span::new(4, 5)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::RParen, Span::new(4, 5)))));
         assert_eq!(lexer.next(), None);
 
         let code = "( + 1 2 )";
         let lexer = Lexer::new(&code);
         let mut lexer = lexer.peekable();
-        assert_eq!(lexer.peek(), Some(&Token::LParen));
-        assert_eq!(lexer.next(), Some(Token::LParen));
-        assert_eq!(lexer.peek(), Some(&Token::Plus));
-        assert_eq!(lexer.next(), Some(Token::Plus));
-        assert_eq!(lexer.peek(), Some(&Token::Number(1)));
-        assert_eq!(lexer.next(), Some(Token::Number(1)));
-        assert_eq!(lexer.peek(), Some(&Token::Number(2)));
-        assert_eq!(lexer.next(), Some(Token::Number(2)));
-        assert_eq!(lexer.peek(), Some(&Token::RParen));
-        assert_eq!(lexer.next(), Some(Token::RParen));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Plus, Span::new(2, 3)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Plus, Span::new(2, 3)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(1), Span::new(4, 5)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(1), Span::new(4, 5)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(2), Span::new(6, 7)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(2), Span::new(6, 7)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::RParen, Span::new(8, 9)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::RParen, Span::new(8, 9)))));
         assert_eq!(lexer.next(), None);
 
         let code = "(= 1 2)";
         let lexer = Lexer::new(&code);
         let mut lexer = lexer.peekable();
-        assert_eq!(lexer.peek(), Some(&Token::LParen));
-        assert_eq!(lexer.next(), Some(Token::LParen));
-        assert_eq!(lexer.peek(), Some(&Token::Equals));
-        assert_eq!(lexer.next(), Some(Token::Equals));
-        assert_eq!(lexer.peek(), Some(&Token::Number(1)));
-        assert_eq!(lexer.next(), Some(Token::Number(1)));
-        assert_eq!(lexer.peek(), Some(&Token::Number(2)));
-        assert_eq!(lexer.next(), Some(Token::Number(2)));
-        assert_eq!(lexer.peek(), Some(&Token::RParen));
-        assert_eq!(lexer.next(), Some(Token::RParen));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Equals, Span::new(1, 2)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Equals, Span::new(1, 2)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(1), Span::new(3, 4)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(1), Span::new(3, 4)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(2), Span::new(5, 6)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(2), Span::new(5, 6)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::RParen, Span::new(6, 7)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::RParen, Span::new(6, 7)))));
         assert_eq!(lexer.next(), None);
 
         let code = "(= 1 (+ 2 3))";
         let lexer = Lexer::new(&code);
         let mut lexer = lexer.peekable();
-        assert_eq!(lexer.peek(), Some(&Token::LParen));
-        assert_eq!(lexer.next(), Some(Token::LParen));
-        assert_eq!(lexer.peek(), Some(&Token::Equals));
-        assert_eq!(lexer.next(), Some(Token::Equals));
-        assert_eq!(lexer.peek(), Some(&Token::Number(1)));
-        assert_eq!(lexer.next(), Some(Token::Number(1)));
-        assert_eq!(lexer.next(), Some(Token::LParen));
-        assert_eq!(lexer.next(), Some(Token::Plus));
-        assert_eq!(lexer.peek(), Some(&Token::Number(2)));
-        assert_eq!(lexer.next(), Some(Token::Number(2)));
-        assert_eq!(lexer.next(), Some(Token::Number(3)));
-        assert_eq!(lexer.next(), Some(Token::RParen));
-        assert_eq!(lexer.peek(), Some(&Token::RParen));
-        assert_eq!(lexer.next(), Some(Token::RParen));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::LParen, Span::new(0, 1)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Equals, Span::new(1, 2)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Equals, Span::new(1, 2)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(1), Span::new(3, 4)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(1), Span::new(3, 4)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::LParen, Span::new(5, 6)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Plus, Span::new(6, 7)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::Integer(2), Span::new(8, 9)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(2), Span::new(8, 9)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::Integer(3), Span::new(10, 11)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::RParen, Span::new(11, 12)))));
+        assert_eq!(lexer.peek(), Some(&Ok((Token::RParen, Span::new(12, 13)))));
+        assert_eq!(lexer.next(), Some(Ok((Token::RParen, Span::new(12, 13)))));
         assert_eq!(lexer.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_lex_function() {
+        let code = "(+ 1 2)";
+        let tokens = lex(code);
+        assert_eq!(tokens, Ok(vec![
+            (Token::LParen, Span::new(0, 1)),
+            (Token::Plus, Span::new(1, 2)),
+            (Token::Integer(1), Span::new(3, 4)),
+            (Token::Integer(2), Span::new(5, 6)),
+            (Token::RParen, Span::new(6, 7)),
+        ]));
+    }
+
+    #[test]
+    fn test_lex_function_reports_unexpected_char() {
+        let code = "(+ 1 ~)";
+        let err = lex(code).unwrap_err();
+        assert_eq!(err, LexError::UnexpectedChar('~', 5));
+    }
+}
@@ -0,0 +1,327 @@
+use crate::lexer::lexer::{Lexer, LexError, Span, Token};
+use std::iter::Peekable;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expr {
+    Number(i64),
+    Float(f64),
+    Ratio(i64, i64),
+    Str(String),
+    Symbol(String),
+    Bool(bool),
+    Nil,
+    BinaryOp { op: Token, lhs: Box<Expr>, rhs: Box<Expr> },
+    List(Vec<Expr>),
+    Quote(Box<Expr>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseError {
+    UnexpectedToken(Token, Span),
+    MissingCloseParen(Span),
+    TrailingInput(Token, Span),
+    UnexpectedEnd,
+    Lex(LexError),
+}
+
+impl From<LexError> for ParseError {
+    fn from(error: LexError) -> Self {
+        ParseError::Lex(error)
+    }
+}
+
+pub fn parse(code: &str) -> Result<Expr, ParseError> {
+    Parser::new(code).parse()
+}
+
+pub struct Parser<'a> {
+    tokens: Peekable<Lexer<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(code: &'a str) -> Self {
+        Parser { tokens: Lexer::new(code).peekable() }
+    }
+
+    pub fn parse(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.parse_expr(0)?;
+        match self.next()? {
+            Some((token, span)) => Err(ParseError::TrailingInput(token, span)),
+            None => Ok(expr),
+        }
+    }
+
+    // Precedence-climbing loop: parse a primary, then keep folding in
+    // infix operators whose left binding power meets `min_bp`.
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_primary()?;
+        loop {
+            let op = match self.peek_token()? {
+                Some(token) => match Self::infix_binding_power(token) {
+                    Some((lbp, _)) if lbp >= min_bp => token.clone(),
+                    _ => break,
+                },
+                None => break,
+            };
+            let (_, rbp) = Self::infix_binding_power(&op).unwrap();
+            self.next()?;
+            let rhs = self.parse_expr(rbp)?;
+            lhs = Expr::BinaryOp { op, lhs: Box::new(lhs), rhs: Box::new(rhs) };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let (token, span) = self.next()?.ok_or(ParseError::UnexpectedEnd)?;
+        self.primary_from(token, span)
+    }
+
+    fn primary_from(&mut self, token: Token, span: Span) -> Result<Expr, ParseError> {
+        match token {
+            Token::Integer(n) => Ok(Expr::Number(n)),
+            Token::Float(n) => Ok(Expr::Float(n)),
+            Token::Ratio(num, den) => Ok(Expr::Ratio(num, den)),
+            Token::String(s) => Ok(Expr::Str(s)),
+            Token::Symbol(s) => Ok(Expr::Symbol(s)),
+            Token::True => Ok(Expr::Bool(true)),
+            Token::False => Ok(Expr::Bool(false)),
+            Token::Nil => Ok(Expr::Nil),
+            Token::Apostrophe => Ok(Expr::Quote(Box::new(self.parse_expr(0)?))),
+            Token::LParen => self.parse_list(),
+            other => Err(ParseError::UnexpectedToken(other, span)),
+        }
+    }
+
+    // `(op operand...)` for a recognized binary operator folds the operands
+    // left-to-right into nested BinaryOps (so `(+ 1 2 3)` is `(1 + 2) + 3`);
+    // anything else is a plain list of recursively parsed items.
+    fn parse_list(&mut self) -> Result<Expr, ParseError> {
+        if let Some(Token::RParen) = self.peek_token()? {
+            self.next()?;
+            return Ok(Expr::List(Vec::new()));
+        }
+
+        let (head, head_span) = self.next()?.ok_or(ParseError::UnexpectedEnd)?;
+        if Self::infix_binding_power(&head).is_some() {
+            let mut acc = self.parse_expr(0)?;
+            loop {
+                match self.peek_token()? {
+                    Some(Token::RParen) => {
+                        self.next()?;
+                        break;
+                    }
+                    None => return Err(ParseError::UnexpectedEnd),
+                    _ => {
+                        let rhs = self.parse_expr(0)?;
+                        acc = Expr::BinaryOp { op: head.clone(), lhs: Box::new(acc), rhs: Box::new(rhs) };
+                    }
+                }
+            }
+            return Ok(acc);
+        }
+
+        let mut items = vec![self.primary_from(head, head_span)?];
+        loop {
+            match self.peek_token()? {
+                Some(Token::RParen) => {
+                    self.next()?;
+                    break;
+                }
+                None => return Err(ParseError::UnexpectedEnd),
+                _ => items.push(self.parse_expr(0)?),
+            }
+        }
+        Ok(Expr::List(items))
+    }
+
+    fn expect_rparen(&mut self) -> Result<(), ParseError> {
+        match self.next()? {
+            Some((Token::RParen, _)) => Ok(()),
+            Some((_, span)) => Err(ParseError::MissingCloseParen(span)),
+            None => Err(ParseError::UnexpectedEnd),
+        }
+    }
+
+    fn infix_binding_power(token: &Token) -> Option<(u8, u8)> {
+        match token {
+            Token::Plus | Token::Minus => Some((10, 11)),
+            Token::Asterisk | Token::Slash => Some((20, 21)),
+            Token::Equals
+            | Token::LAngleBracket
+            | Token::RAngleBracket
+            | Token::LessEqual
+            | Token::GreaterEqual
+            | Token::EqualEqual
+            | Token::NotEqual => Some((5, 6)),
+            _ => None,
+        }
+    }
+
+    fn next(&mut self) -> Result<Option<(Token, Span)>, ParseError> {
+        self.tokens.next().transpose().map_err(ParseError::from)
+    }
+
+    fn peek_token(&mut self) -> Result<Option<&Token>, ParseError> {
+        match self.tokens.peek() {
+            Some(Ok((token, _))) => Ok(Some(token)),
+            Some(Err(e)) => Err(ParseError::from(*e)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse("42"), Ok(Expr::Number(42)));
+    }
+
+    #[test]
+    fn test_parse_binary_op() {
+        let expect = Ok(Expr::BinaryOp {
+            op: Token::Plus,
+            lhs: Box::new(Expr::Number(1)),
+            rhs: Box::new(Expr::Number(2)),
+        });
+        assert_eq!(parse("(+ 1 2)"), expect);
+    }
+
+    #[test]
+    fn test_parse_nested_binary_op() {
+        let expect = Ok(Expr::BinaryOp {
+            op: Token::Equals,
+            lhs: Box::new(Expr::Number(1)),
+            rhs: Box::new(Expr::BinaryOp {
+                op: Token::Plus,
+                lhs: Box::new(Expr::Number(2)),
+                rhs: Box::new(Expr::Number(3)),
+            }),
+        });
+        assert_eq!(parse("(= 1 (+ 2 3))"), expect);
+    }
+
+    #[test]
+    fn test_parse_precedence_climbing() {
+        // `*` binds tighter than `+`, so `2 + 3 * 4` should read as `2 + (3 * 4)`.
+        let expect = Ok(Expr::BinaryOp {
+            op: Token::Plus,
+            lhs: Box::new(Expr::Number(2)),
+            rhs: Box::new(Expr::BinaryOp {
+                op: Token::Asterisk,
+                lhs: Box::new(Expr::Number(3)),
+                rhs: Box::new(Expr::Number(4)),
+            }),
+        });
+        assert_eq!(parse("2+3*4"), expect);
+    }
+
+    #[test]
+    fn test_parse_quote() {
+        let expect = Ok(Expr::Quote(Box::new(Expr::BinaryOp {
+            op: Token::Plus,
+            lhs: Box::new(Expr::Number(1)),
+            rhs: Box::new(Expr::Number(2)),
+        })));
+        assert_eq!(parse("'(+ 1 2)"), expect);
+    }
+
+    #[test]
+    fn test_parse_variadic_binary_op() {
+        // `(+ 1 2 3)` folds left-to-right: `(1 + 2) + 3`.
+        let expect = Ok(Expr::BinaryOp {
+            op: Token::Plus,
+            lhs: Box::new(Expr::BinaryOp {
+                op: Token::Plus,
+                lhs: Box::new(Expr::Number(1)),
+                rhs: Box::new(Expr::Number(2)),
+            }),
+            rhs: Box::new(Expr::Number(3)),
+        });
+        assert_eq!(parse("(+ 1 2 3)"), expect);
+
+        let expect = Ok(Expr::BinaryOp {
+            op: Token::Asterisk,
+            lhs: Box::new(Expr::BinaryOp {
+                op: Token::Asterisk,
+                lhs: Box::new(Expr::Number(2)),
+                rhs: Box::new(Expr::Number(3)),
+            }),
+            rhs: Box::new(Expr::Number(4)),
+        });
+        assert_eq!(parse("(* 2 3 4)"), expect);
+    }
+
+    #[test]
+    fn test_parse_comparison_ops() {
+        for (code, op) in [
+            ("(<= 1 2)", Token::LessEqual),
+            ("(>= 1 2)", Token::GreaterEqual),
+            ("(== 1 2)", Token::EqualEqual),
+            ("(!= 1 2)", Token::NotEqual),
+        ] {
+            let expect = Ok(Expr::BinaryOp {
+                op,
+                lhs: Box::new(Expr::Number(1)),
+                rhs: Box::new(Expr::Number(2)),
+            });
+            assert_eq!(parse(code), expect);
+        }
+    }
+
+    #[test]
+    fn test_parse_single_operand_binary_op() {
+        assert_eq!(parse("(+ 1)"), Ok(Expr::Number(1)));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let expect = Ok(Expr::List(vec![Expr::Number(1), Expr::Number(2), Expr::Number(3)]));
+        assert_eq!(parse("(1 2 3)"), expect);
+    }
+
+    #[test]
+    fn test_parse_empty_list() {
+        assert_eq!(parse("()"), Ok(Expr::List(Vec::new())));
+    }
+
+    #[test]
+    fn test_parse_missing_close_paren() {
+        let err = parse("(+ 1 2").unwrap_err();
+        assert_eq!(err, ParseError::UnexpectedEnd);
+    }
+
+    #[test]
+    fn test_parse_unexpected_token() {
+        let err = parse(")").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken(Token::RParen, _)));
+    }
+
+    #[test]
+    fn test_parse_literals() {
+        assert_eq!(parse("12.34"), Ok(Expr::Float(12.34)));
+        assert_eq!(parse("1/2"), Ok(Expr::Ratio(1, 2)));
+        assert_eq!(parse("\"hi\""), Ok(Expr::Str("hi".to_string())));
+        assert_eq!(parse("my-var"), Ok(Expr::Symbol("my-var".to_string())));
+        assert_eq!(parse("true"), Ok(Expr::Bool(true)));
+        assert_eq!(parse("false"), Ok(Expr::Bool(false)));
+        assert_eq!(parse("nil"), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn test_parse_rejects_trailing_tokens() {
+        let err = parse("1 2").unwrap_err();
+        assert!(matches!(err, ParseError::TrailingInput(Token::Integer(2), _)));
+
+        let err = parse("(+ 1 2) (+ 3 4)").unwrap_err();
+        assert!(matches!(err, ParseError::TrailingInput(Token::LParen, _)));
+    }
+
+    #[test]
+    fn test_parse_propagates_lex_error() {
+        let err = parse("(+ 1 ~)").unwrap_err();
+        assert!(matches!(err, ParseError::Lex(LexError::UnexpectedChar('~', 5))));
+    }
+}